@@ -0,0 +1,77 @@
+//! Adaptive spin/yield backoff for lock-free retry loops.
+
+use core::hint::spin_loop;
+
+/// Step at which [`Backoff`] stops escalating its spin count and (under
+/// `std`) starts yielding the thread instead.
+const SPIN_LIMIT: u32 = 6;
+
+/// Incrementing backoff for hot retry loops.
+///
+/// Spins with [`core::hint::spin_loop`] hints, doubling the spin count each
+/// call up to [`SPIN_LIMIT`], then (under `std`) yields the thread -- the
+/// same escalation crossbeam and thingbuf use in their retry loops.
+#[derive(Debug, Default)]
+pub struct Backoff {
+    step: u32,
+}
+
+impl Backoff {
+    /// Create a fresh backoff at its minimum step.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { step: 0 }
+    }
+
+    /// Reset to the minimum step, e.g. after a successful operation.
+    pub fn reset(&mut self) {
+        self.step = 0;
+    }
+
+    /// Whether this backoff has escalated past pure spinning.
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.step > SPIN_LIMIT
+    }
+
+    /// Spin (or yield) once, escalating the backoff for next time.
+    pub fn spin(&mut self) {
+        let spins = 1u32 << self.step.min(SPIN_LIMIT);
+        for _ in 0..spins {
+            spin_loop();
+        }
+        if self.step <= SPIN_LIMIT {
+            self.step += 1;
+        }
+        #[cfg(feature = "std")]
+        if self.is_completed() {
+            std::thread::yield_now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completes_after_spin_limit_steps() {
+        let mut backoff = Backoff::new();
+        assert!(!backoff.is_completed());
+        for _ in 0..=SPIN_LIMIT {
+            backoff.spin();
+        }
+        assert!(backoff.is_completed());
+    }
+
+    #[test]
+    fn reset_returns_to_fresh_state() {
+        let mut backoff = Backoff::new();
+        for _ in 0..=SPIN_LIMIT {
+            backoff.spin();
+        }
+        assert!(backoff.is_completed());
+        backoff.reset();
+        assert!(!backoff.is_completed());
+    }
+}
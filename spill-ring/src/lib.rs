@@ -3,21 +3,36 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
+mod backoff;
 mod index;
 mod iter;
+#[cfg(feature = "atomics")]
+mod mpmc;
 mod mpsc;
+#[cfg(feature = "atomics")]
+mod pool;
 mod read;
+mod recycle;
 mod ring;
 mod spsc;
 mod traits;
+#[cfg(feature = "std")]
+mod waiter;
 
 #[cfg(test)]
 mod tests;
 
 pub use iter::{SpillRingIter, SpillRingIterMut};
+#[cfg(feature = "atomics")]
+pub use mpmc::MpmcRing;
 pub use mpsc::{Consumer, MpscRing, Producer, collect};
 #[cfg(feature = "std")]
 pub use mpsc::{PoolBuilder, WorkerPool};
-pub use ring::SpillRing;
+#[cfg(feature = "atomics")]
+pub use pool::PoolSpout;
+pub use recycle::Recycle;
+#[cfg(feature = "async")]
+pub use ring::RingStream;
+pub use ring::{PopRefGuard, PushRefGuard, SpillRing};
 pub use spsc::SpscRing;
 pub use traits::{RingConsumer, RingInfo, RingProducer, RingTrait};
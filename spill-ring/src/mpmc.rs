@@ -0,0 +1,381 @@
+//! Multi-producer/multi-consumer ring variant.
+//!
+//! [`crate::SpillRing`] is SPSC-only. `MpmcRing` relaxes that to any number
+//! of concurrent producers and consumers, using the classic bounded-queue
+//! per-slot-sequence algorithm (as used by crossbeam's `ArrayQueue`), while
+//! preserving `SpillRing`'s eviction-on-overflow semantics.
+
+use core::cell::UnsafeCell;
+use core::hint::spin_loop;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use spout::{DropSpout, Spout};
+
+use crate::index::SpoutCell;
+use crate::traits::RingInfo;
+
+struct Slot<T> {
+    /// Sequence stamp. Starts at the slot's index; after a write it becomes
+    /// `index + 1`, and after a read it becomes `index + N`, ready for the
+    /// next lap. Producers/consumers compare this against their own
+    /// position to decide whether a slot is free, full, or stale.
+    stamp: AtomicUsize,
+    data: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T> Slot<T> {
+    fn new(index: usize) -> Self {
+        Self {
+            stamp: AtomicUsize::new(index),
+            data: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+}
+
+/// Maximum supported capacity (2^20 = ~1 million slots), mirroring [`crate::SpillRing`].
+const MAX_CAPACITY: usize = 1 << 20;
+
+/// Bounded MPMC ring buffer that spills evicted items to a spout.
+///
+/// Safe for any number of concurrent producers and consumers, unlike
+/// [`crate::SpillRing`] which is SPSC-only.
+pub struct MpmcRing<T, const N: usize, S: Spout<T> = DropSpout> {
+    enqueue_pos: AtomicUsize,
+    dequeue_pos: AtomicUsize,
+    buffer: [Slot<T>; N],
+    sink: SpoutCell<S>,
+    /// Serializes concurrent evictions' calls into `sink`, since more than
+    /// one producer can win an eviction CAS at the same time (for different
+    /// slots) and `Spout::send` otherwise assumes a single caller.
+    sink_lock: AtomicBool,
+}
+
+unsafe impl<T: Send, const N: usize, S: Spout<T> + Send> Send for MpmcRing<T, N, S> {}
+unsafe impl<T: Send, const N: usize, S: Spout<T> + Send> Sync for MpmcRing<T, N, S> {}
+
+impl<T, const N: usize> MpmcRing<T, N, DropSpout> {
+    /// Create a new MPMC ring (evicted items are dropped).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_sink(DropSpout)
+    }
+}
+
+impl<T, const N: usize> Default for MpmcRing<T, N, DropSpout> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize, S: Spout<T>> MpmcRing<T, N, S> {
+    /// Create a new MPMC ring with a custom spout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N < 2`. The per-slot sequence stamp distinguishes
+    /// "ready to write" (`stamp == pos`) from "ready to read"
+    /// (`stamp == pos + 1`) only because consecutive positions land on
+    /// *different* slots for `N >= 2`; at `N == 1` every position maps to
+    /// the same slot, so the stamp left by a write (`pos + 1`) is
+    /// bit-identical to the write-ready stamp the very next push checks
+    /// for (since the next push's position is also `pos + 1`), letting a
+    /// second push silently overwrite an unread item instead of evicting
+    /// it to the spout.
+    #[must_use]
+    pub fn with_sink(sink: S) -> Self {
+        assert!(
+            N >= 2,
+            "capacity must be >= 2 (N == 1 collides the write-ready and \
+             read-ready sequence stamps, see with_sink's docs)"
+        );
+        assert!(N.is_power_of_two(), "capacity must be power of two");
+        assert!(N <= MAX_CAPACITY, "capacity exceeds maximum (2^20)");
+
+        Self {
+            enqueue_pos: AtomicUsize::new(0),
+            dequeue_pos: AtomicUsize::new(0),
+            buffer: core::array::from_fn(Slot::new),
+            sink: SpoutCell::new(sink),
+            sink_lock: AtomicBool::new(false),
+        }
+    }
+
+    fn evict_to_sink(&self, item: T) {
+        while self
+            .sink_lock
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            spin_loop();
+        }
+        unsafe { self.sink.get_mut_unchecked().send(item) };
+        self.sink_lock.store(false, Ordering::Release);
+    }
+
+    /// Push an item. If full, evicts the oldest item to the spout.
+    ///
+    /// Safe to call concurrently from any number of producers and
+    /// alongside any number of concurrent [`pop`](Self::pop) calls.
+    pub fn push(&self, item: T) {
+        loop {
+            let pos = self.enqueue_pos.load(Ordering::Relaxed);
+            let slot = &self.buffer[pos & (N - 1)];
+            let stamp = slot.stamp.load(Ordering::Acquire);
+            let diff = stamp as isize - pos as isize;
+
+            if diff == 0 {
+                if self
+                    .enqueue_pos
+                    .compare_exchange_weak(
+                        pos,
+                        pos.wrapping_add(1),
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+                {
+                    unsafe { (*slot.data.get()).write(item) };
+                    slot.stamp.store(pos.wrapping_add(1), Ordering::Release);
+                    return;
+                }
+            } else if diff < 0 {
+                // Ring full. One producer wins the CAS to claim the oldest
+                // slot by advancing `dequeue_pos`, then spills it before
+                // retrying its own push; everyone else just retries.
+                let dequeue = self.dequeue_pos.load(Ordering::Relaxed);
+                if self
+                    .dequeue_pos
+                    .compare_exchange_weak(
+                        dequeue,
+                        dequeue.wrapping_add(1),
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+                {
+                    let evict_slot = &self.buffer[dequeue & (N - 1)];
+                    // The producer that filled this slot may not have
+                    // published its stamp yet -- wait for it.
+                    while evict_slot.stamp.load(Ordering::Acquire) != dequeue.wrapping_add(1) {
+                        spin_loop();
+                    }
+                    let evicted = unsafe { (*evict_slot.data.get()).assume_init_read() };
+                    evict_slot
+                        .stamp
+                        .store(dequeue.wrapping_add(N), Ordering::Release);
+                    self.evict_to_sink(evicted);
+                }
+            } else {
+                spin_loop();
+            }
+        }
+    }
+
+    /// Pop the oldest item, or `None` if the ring is empty.
+    ///
+    /// Safe to call concurrently from any number of consumers and
+    /// alongside any number of concurrent [`push`](Self::push) calls.
+    #[must_use]
+    pub fn pop(&self) -> Option<T> {
+        loop {
+            let pos = self.dequeue_pos.load(Ordering::Relaxed);
+            let slot = &self.buffer[pos & (N - 1)];
+            let stamp = slot.stamp.load(Ordering::Acquire);
+            let diff = stamp as isize - pos.wrapping_add(1) as isize;
+
+            if diff == 0 {
+                if self
+                    .dequeue_pos
+                    .compare_exchange_weak(
+                        pos,
+                        pos.wrapping_add(1),
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+                {
+                    let item = unsafe { (*slot.data.get()).assume_init_read() };
+                    slot.stamp.store(pos.wrapping_add(N), Ordering::Release);
+                    return Some(item);
+                }
+            } else if diff < 0 {
+                return None;
+            } else {
+                spin_loop();
+            }
+        }
+    }
+
+    /// Buffer capacity.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Number of items currently in the ring.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        let tail = self.enqueue_pos.load(Ordering::Acquire);
+        let head = self.dequeue_pos.load(Ordering::Acquire);
+        let len = tail.wrapping_sub(head);
+        if len > N { N } else { len }
+    }
+
+    /// True if empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// True if full.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.len() >= N
+    }
+}
+
+impl<T, const N: usize, S: Spout<T>> RingInfo for MpmcRing<T, N, S> {
+    #[inline]
+    fn len(&self) -> usize {
+        MpmcRing::len(self)
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<T, const N: usize, S: Spout<T>> Drop for MpmcRing<T, N, S> {
+    fn drop(&mut self) {
+        while let Some(item) = self.pop() {
+            self.sink.get_mut().send(item);
+        }
+        self.sink.get_mut().flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_pop_fifo_order() {
+        let ring: MpmcRing<u32, 4> = MpmcRing::new();
+        ring.push(1);
+        ring.push(2);
+        ring.push(3);
+        assert_eq!(ring.pop(), Some(1));
+        assert_eq!(ring.pop(), Some(2));
+        assert_eq!(ring.pop(), Some(3));
+        assert_eq!(ring.pop(), None);
+    }
+
+    #[test]
+    fn push_evicts_oldest_when_full() {
+        let ring: MpmcRing<u32, 2> = MpmcRing::new();
+        ring.push(1);
+        ring.push(2);
+        ring.push(3); // evicts 1
+        assert_eq!(ring.pop(), Some(2));
+        assert_eq!(ring.pop(), Some(3));
+        assert_eq!(ring.pop(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be >= 2")]
+    fn with_sink_rejects_capacity_one() {
+        let _ring: MpmcRing<u32, 1> = MpmcRing::new();
+    }
+
+    /// Several producer threads racing `push` against several consumer
+    /// threads racing `pop`, with every item that doesn't make it out via
+    /// `pop` accounted for by the spout instead (the eviction path). An item
+    /// overwritten without being evicted would vanish from both counts.
+    /// (N == 1, where the write-ready and read-ready stamps collide, is
+    /// rejected by `with_sink`; see `with_sink_rejects_capacity_one`.)
+    #[test]
+    fn concurrent_push_pop_accounts_for_every_item_exactly_once() {
+        use std::sync::Mutex;
+        use std::thread;
+
+        struct CountingSpout(AtomicUsize);
+
+        impl Spout<usize> for CountingSpout {
+            fn send(&mut self, _item: usize) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+
+            fn flush(&mut self) {}
+        }
+
+        const PRODUCERS: usize = 4;
+        const CONSUMERS: usize = 4;
+        const PER_PRODUCER: usize = 2_000;
+        const TOTAL: usize = PRODUCERS * PER_PRODUCER;
+
+        let ring: MpmcRing<usize, 16, CountingSpout> =
+            MpmcRing::with_sink(CountingSpout(AtomicUsize::new(0)));
+        let popped = Mutex::new(Vec::with_capacity(TOTAL));
+        let done = AtomicBool::new(false);
+
+        thread::scope(|s| {
+            let producers: Vec<_> = (0..PRODUCERS)
+                .map(|p| {
+                    let ring = &ring;
+                    s.spawn(move || {
+                        for i in 0..PER_PRODUCER {
+                            ring.push(p * PER_PRODUCER + i);
+                        }
+                    })
+                })
+                .collect();
+
+            let consumers: Vec<_> = (0..CONSUMERS)
+                .map(|_| {
+                    let ring = &ring;
+                    let popped = &popped;
+                    let done = &done;
+                    s.spawn(move || {
+                        let mut local = Vec::new();
+                        loop {
+                            match ring.pop() {
+                                Some(item) => local.push(item),
+                                None if done.load(Ordering::Acquire) => break,
+                                None => spin_loop(),
+                            }
+                        }
+                        popped.lock().unwrap().extend(local);
+                    })
+                })
+                .collect();
+
+            for producer in producers {
+                producer.join().unwrap();
+            }
+            // Every push has landed; let consumers drain what's left and stop.
+            done.store(true, Ordering::Release);
+            for consumer in consumers {
+                consumer.join().unwrap();
+            }
+        });
+
+        let mut popped = popped.into_inner().unwrap();
+        let evicted = ring.sink.get_ref().0.load(Ordering::Relaxed);
+        let popped_count = popped.len();
+        assert_eq!(
+            popped_count + evicted,
+            TOTAL,
+            "every pushed item must be either popped or evicted exactly once"
+        );
+
+        popped.sort_unstable();
+        popped.dedup();
+        assert_eq!(
+            popped.len(),
+            popped_count,
+            "no popped item should be a duplicate"
+        );
+    }
+}
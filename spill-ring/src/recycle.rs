@@ -0,0 +1,16 @@
+//! Slot recycling for in-place `push_ref`/`pop_ref` access.
+
+/// Policy for resetting a slot for reuse instead of reconstructing it.
+///
+/// Used by [`SpillRing::warm_with`](crate::SpillRing::warm_with) to
+/// pre-populate every slot, and by
+/// [`SpillRing::push_ref`](crate::SpillRing::push_ref) to reset every slot
+/// it hands out in place, evicted or not (e.g. `Vec::clear` keeps the
+/// allocation instead of dropping and reallocating it next cycle).
+pub trait Recycle<T> {
+    /// Construct a new element to pre-populate a slot.
+    fn new_element(&self) -> T;
+
+    /// Reset `element` for reuse in place.
+    fn recycle(&self, element: &mut T);
+}
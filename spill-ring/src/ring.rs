@@ -3,12 +3,17 @@
 use core::{cell::UnsafeCell, mem::MaybeUninit};
 
 use crate::{
+    backoff::Backoff,
     index::{Index, SpoutCell},
     iter::SpillRingIterMut,
+    recycle::Recycle,
     traits::{RingConsumer, RingInfo, RingProducer},
 };
 use spout::{DropSpout, Spout};
 
+#[cfg(feature = "std")]
+use crate::waiter::Waiter;
+
 /// Slot wrapper holding one item in the ring buffer.
 ///
 /// `#[repr(transparent)]` guarantees `[Slot<T>; N]` has the same layout
@@ -26,9 +31,50 @@ impl<T> Slot<T> {
     }
 }
 
-/// Target cache-line size in bytes. 64 bytes is correct for x86-64 and most
-/// ARM64 server cores. Adjust if targeting a platform with a different line
-/// size (e.g. 128 bytes on Apple M-series, 32 bytes on some embedded cores).
+/// Target cache-line size in bytes for the current architecture, used to
+/// pad `head`/`tail` apart and prevent false sharing.
+///
+/// Mirrors crossbeam-utils's `CachePadded` table: x86-64, AArch64 and
+/// POWER8+ cores fetch 128-byte lines; a handful of other architectures
+/// use narrower or wider lines; everything else defaults to 64.
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "powerpc64",
+))]
+const CACHE_LINE: usize = 128;
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "mips32r6",
+    target_arch = "mips64",
+    target_arch = "mips64r6",
+    target_arch = "sparc",
+    target_arch = "hexagon",
+))]
+const CACHE_LINE: usize = 32;
+
+#[cfg(target_arch = "m68k")]
+const CACHE_LINE: usize = 16;
+
+#[cfg(target_arch = "s390x")]
+const CACHE_LINE: usize = 256;
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "powerpc64",
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "mips32r6",
+    target_arch = "mips64",
+    target_arch = "mips64r6",
+    target_arch = "sparc",
+    target_arch = "hexagon",
+    target_arch = "m68k",
+    target_arch = "s390x",
+)))]
 const CACHE_LINE: usize = 64;
 
 /// Padding to fill the consumer cache line (head + cached_tail + pad = CACHE_LINE).
@@ -65,6 +111,18 @@ pub struct SpillRing<T, const N: usize, S: Spout<T> = DropSpout> {
     // ── Cold fields ──────────────────────────────────────────────────
     pub(crate) buffer: [Slot<T>; N],
     sink: SpoutCell<S>,
+    /// Set by [`warm_with`](Self::warm_with): once every slot holds a valid
+    /// `T` for `push_ref`/`pop_ref` to hand out `&mut T`/`&T` into, it stays
+    /// that way for the ring's whole life (`push_ref` recycles in place,
+    /// never uninitializes), unlike the move-semantics `push`/`pop` model
+    /// where only the `[head, tail)` window is physically initialized.
+    /// `Drop` reads this to decide whether to drop all `N` slots or just
+    /// that window.
+    warmed: bool,
+    /// Lets a consumer block (or register a `Waker`) instead of
+    /// busy-polling; see [`pop_blocking`](Self::pop_blocking).
+    #[cfg(feature = "std")]
+    waiter: Waiter,
 }
 
 unsafe impl<T: Send, const N: usize, S: Spout<T> + Send> Send for SpillRing<T, N, S> {}
@@ -76,28 +134,56 @@ unsafe impl<T: Send, const N: usize, S: Spout<T> + Send> Sync for SpillRing<T, N
 /// Prevents accidental huge allocations from typos like `SpillRing<T, 1000000000>`.
 const MAX_CAPACITY: usize = 1 << 20;
 
+/// Assert that `head` and `tail` land on separate cache lines, and that
+/// each index's `Cell` cache sits on its *own* writer's line, for this
+/// concrete `SpillRing<T, N, S>`.
+///
+/// Checked at every monomorphization (not just the `layout_tests` type),
+/// since the offsets depend on `size_of::<T>()` only through `buffer`'s
+/// placement -- not on `head`/`tail` -- but a future field reshuffle could
+/// silently reintroduce false sharing for some instantiation without this.
+const fn assert_no_false_sharing<T, const N: usize, S: Spout<T>>() {
+    let head_offset = core::mem::offset_of!(SpillRing<T, N, S>, head);
+    let cached_tail_offset = core::mem::offset_of!(SpillRing<T, N, S>, cached_tail);
+    let tail_offset = core::mem::offset_of!(SpillRing<T, N, S>, tail);
+    let cached_head_offset = core::mem::offset_of!(SpillRing<T, N, S>, cached_head);
+    let evict_head_offset = core::mem::offset_of!(SpillRing<T, N, S>, evict_head);
+
+    assert!(
+        head_offset / CACHE_LINE != tail_offset / CACHE_LINE,
+        "head and tail must be on different cache lines"
+    );
+    assert!(
+        cached_tail_offset / CACHE_LINE == head_offset / CACHE_LINE,
+        "cached_tail must share cache line with head"
+    );
+    assert!(
+        cached_head_offset / CACHE_LINE == tail_offset / CACHE_LINE,
+        "cached_head must share cache line with tail"
+    );
+    assert!(
+        evict_head_offset / CACHE_LINE == tail_offset / CACHE_LINE,
+        "evict_head must share cache line with tail"
+    );
+}
+
 impl<T, const N: usize> SpillRing<T, N, DropSpout> {
-    /// Create a new ring buffer with pre-warmed cache (evicted items are dropped).
+    /// Create a new ring buffer (evicted items are dropped).
     ///
-    /// All buffer slots are touched to bring memory into L1/L2 cache before
-    /// the ring is returned. This is the recommended default for all use cases.
-    #[must_use]
-    pub fn new() -> Self {
-        let ring = Self::cold();
-        ring.warm();
-        ring
-    }
-
-    /// Create a new ring buffer without cache warming (evicted items are dropped).
+    /// A `const fn`, so a `SpillRing` can live in a `static`.
     ///
-    /// Use this only in constrained environments (embedded, const contexts)
-    /// where the warming overhead is unacceptable. Prefer [`new()`](Self::new)
-    /// for all other cases.
+    /// Breaking behavior change: slots are no longer pre-warmed
+    /// automatically as they were in previous versions of this crate --
+    /// cache-warming is a runtime-only operation and can't happen inside a
+    /// `const fn`. Every existing caller of `new()` now gets cold slots, not
+    /// warm ones; call [`warm()`](Self::warm) explicitly afterwards if you
+    /// relied on the old eager L1/L2 fault-in.
     #[must_use]
-    pub const fn cold() -> Self {
+    pub const fn new() -> Self {
         const { assert!(N > 0, "capacity must be > 0") };
         const { assert!(N.is_power_of_two(), "capacity must be power of two") };
         const { assert!(N <= MAX_CAPACITY, "capacity exceeds maximum (2^20)") };
+        const { assert_no_false_sharing::<T, N, DropSpout>() };
 
         Self {
             head: Index::new(0),
@@ -109,28 +195,39 @@ impl<T, const N: usize> SpillRing<T, N, DropSpout> {
             _pad_tail: [0; TAIL_PAD],
             buffer: [const { Slot::new() }; N],
             sink: SpoutCell::new(DropSpout),
+            warmed: false,
+            #[cfg(feature = "std")]
+            waiter: Waiter::new(),
         }
     }
-}
 
-impl<T, const N: usize, S: Spout<T>> SpillRing<T, N, S> {
-    /// Create a new ring buffer with pre-warmed cache and a custom spout.
+    /// Deprecated alias for [`new()`](Self::new).
+    ///
+    /// `cold()` predates `new()` becoming a `const fn` that skips warming by
+    /// default; now that `new()` does exactly what `cold()` did, there's no
+    /// reason to have both.
     #[must_use]
-    pub fn with_sink(sink: S) -> Self {
-        let ring = Self::with_sink_cold(sink);
-        ring.warm();
-        ring
+    #[deprecated(note = "identical to `new()` now that `new()` no longer warms by default; use `new()`")]
+    pub const fn cold() -> Self {
+        Self::new()
     }
+}
 
-    /// Create a new ring buffer with a custom spout, without cache warming.
+impl<T, const N: usize, S: Spout<T>> SpillRing<T, N, S> {
+    /// Create a new ring buffer with a custom spout.
+    ///
+    /// A `const fn`, so a `SpillRing` can live in a `static`.
     ///
-    /// Use this only in constrained environments. Prefer [`with_sink()`](Self::with_sink)
-    /// for all other cases.
+    /// Breaking behavior change: slots are no longer pre-warmed
+    /// automatically, for the same reason as [`new()`](SpillRing::new) --
+    /// call [`warm()`](Self::warm) explicitly afterwards if you relied on
+    /// the old eager L1/L2 fault-in.
     #[must_use]
-    pub fn with_sink_cold(sink: S) -> Self {
+    pub const fn with_sink(sink: S) -> Self {
         const { assert!(N > 0, "capacity must be > 0") };
         const { assert!(N.is_power_of_two(), "capacity must be power of two") };
         const { assert!(N <= MAX_CAPACITY, "capacity exceeds maximum (2^20)") };
+        const { assert_no_false_sharing::<T, N, S>() };
 
         Self {
             head: Index::new(0),
@@ -142,17 +239,35 @@ impl<T, const N: usize, S: Spout<T>> SpillRing<T, N, S> {
             _pad_tail: [0; TAIL_PAD],
             buffer: [const { Slot::new() }; N],
             sink: SpoutCell::new(sink),
+            warmed: false,
+            #[cfg(feature = "std")]
+            waiter: Waiter::new(),
         }
     }
 
+    /// Deprecated alias for [`with_sink()`](Self::with_sink).
+    ///
+    /// `with_sink_cold()` predates `with_sink()` becoming a `const fn` that
+    /// skips warming by default; now that `with_sink()` does exactly what
+    /// `with_sink_cold()` did, there's no reason to have both.
+    #[must_use]
+    #[deprecated(
+        note = "identical to `with_sink()` now that `with_sink()` no longer warms by default; use `with_sink()`"
+    )]
+    pub const fn with_sink_cold(sink: S) -> Self {
+        Self::with_sink(sink)
+    }
+
     /// Bring all ring slots into L1/L2 cache.
     ///
     /// Touches every slot with a volatile write to fault the memory pages
     /// and pull cache lines into the CPU's local cache hierarchy. Indices
     /// are reset afterwards -- no items are logically added to the ring.
     ///
-    /// Called automatically by [`new()`](SpillRing::new) and [`with_sink()`](Self::with_sink).
-    fn warm(&self) {
+    /// No longer called automatically by [`new()`](SpillRing::new) or
+    /// [`with_sink()`](Self::with_sink) -- call this explicitly after
+    /// construction if you want the eager warming.
+    pub fn warm(&self) {
         for i in 0..N {
             unsafe {
                 let slot = &self.buffer[i];
@@ -210,6 +325,8 @@ impl<T, const N: usize, S: Spout<T>> SpillRing<T, N, S> {
         let idx = tail & (N - 1);
         unsafe { (*self.buffer[idx].data.get()).write(item) };
         self.tail.store(tail.wrapping_add(1));
+        #[cfg(feature = "std")]
+        self.waiter.notify();
     }
 
     /// Push an item. If full, evicts oldest to spout.
@@ -239,6 +356,8 @@ impl<T, const N: usize, S: Spout<T>> SpillRing<T, N, S> {
 
         unsafe { (*self.buffer[idx].data.get()).write(item) };
         self.tail.store(tail.wrapping_add(1));
+        #[cfg(feature = "std")]
+        self.waiter.notify();
     }
 
     /// Push an item with exclusive access (no atomic overhead).
@@ -261,6 +380,8 @@ impl<T, const N: usize, S: Spout<T>> SpillRing<T, N, S> {
         let idx = tail & (N - 1);
         unsafe { (*self.buffer[idx].data.get()).write(item) };
         self.tail.store_mut(tail.wrapping_add(1));
+        #[cfg(feature = "std")]
+        self.waiter.notify();
     }
 
     /// Push an item with exclusive access (no `Cell`/atomic overhead).
@@ -280,6 +401,8 @@ impl<T, const N: usize, S: Spout<T>> SpillRing<T, N, S> {
         let idx = tail & (N - 1);
         unsafe { (*self.buffer[idx].data.get()).write(item) };
         self.tail.store_mut(tail.wrapping_add(1));
+        #[cfg(feature = "std")]
+        self.waiter.notify();
     }
 
     /// Pop the oldest item with exclusive access (no atomic overhead).
@@ -467,6 +590,7 @@ impl<T, const N: usize, S: Spout<T>> SpillRing<T, N, S> {
     #[must_use]
     #[cfg(feature = "atomics")]
     pub fn pop(&self) -> Option<T> {
+        let mut backoff = Backoff::new();
         loop {
             let mut head = self.head.load_relaxed();
 
@@ -506,6 +630,7 @@ impl<T, const N: usize, S: Spout<T>> SpillRing<T, N, S> {
             if evict2 > head {
                 // Eviction happened during our read. Discard speculative copy.
                 // MaybeUninit<T> has no Drop impl, so this is safe.
+                backoff.spin();
                 continue;
             }
 
@@ -605,6 +730,299 @@ impl<T, const N: usize, S: Spout<T>> SpillRing<T, N, S> {
     pub fn drain(&mut self) -> Drain<'_, T, N, S> {
         Drain { ring: self }
     }
+
+    /// Effective head (accounting for evictions) and clamped length.
+    #[inline]
+    fn occupied_region(&mut self) -> (usize, usize) {
+        let tail = self.tail.load_mut();
+        let mut head = self.head.load_mut();
+        let evict = self.evict_head.load_mut();
+        if head < evict {
+            head = evict;
+        }
+        let len = tail.wrapping_sub(head);
+        (head, if len > N { N } else { len })
+    }
+
+    /// Borrow the ring's occupied region as (up to) two contiguous slices,
+    /// oldest item first.
+    ///
+    /// Mirrors [`alloc::collections::VecDeque::as_slices`]: since the
+    /// buffer wraps, the occupied region may need to be split at the wrap
+    /// point into a tail segment and a head segment. Useful for feeding
+    /// ring contents directly into `writev`/`write_vectored`, SIMD scans, or
+    /// `copy_from_slice` without draining.
+    #[inline]
+    #[must_use]
+    pub fn as_slices(&mut self) -> (&[T], &[T]) {
+        let (head, len) = self.occupied_region();
+        let head_idx = head & (N - 1);
+
+        // Safety: `Slot<T>` is `#[repr(transparent)]` over storage with the
+        // same layout as `T`, so `[Slot<T>; N]` has the same layout as
+        // `[T; N]` (see the comment on `Slot`). `[head_idx, head_idx+len)`
+        // (wrapping) is exactly the occupied region, so every index read is
+        // initialized, and `&mut self` rules out a concurrent writer.
+        unsafe {
+            let ptr = self.buffer.as_ptr().cast::<T>();
+            if head_idx + len <= N {
+                (core::slice::from_raw_parts(ptr.add(head_idx), len), &[])
+            } else {
+                let first_len = N - head_idx;
+                (
+                    core::slice::from_raw_parts(ptr.add(head_idx), first_len),
+                    core::slice::from_raw_parts(ptr, len - first_len),
+                )
+            }
+        }
+    }
+
+    /// Mutable counterpart to [`as_slices`](Self::as_slices).
+    #[inline]
+    #[must_use]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (head, len) = self.occupied_region();
+        let head_idx = head & (N - 1);
+
+        // Safety: see `as_slices`. The two slices never overlap.
+        unsafe {
+            let ptr = self.buffer.as_mut_ptr().cast::<T>();
+            if head_idx + len <= N {
+                (
+                    core::slice::from_raw_parts_mut(ptr.add(head_idx), len),
+                    &mut [],
+                )
+            } else {
+                let first_len = N - head_idx;
+                (
+                    core::slice::from_raw_parts_mut(ptr.add(head_idx), first_len),
+                    core::slice::from_raw_parts_mut(ptr, len - first_len),
+                )
+            }
+        }
+    }
+
+    /// Bulk-pop the oldest items into `out`, returning the count moved.
+    ///
+    /// Uses `memcpy` internally -- at most two copies (tail-to-end segment,
+    /// then wrapped segment) -- instead of draining one at a time through
+    /// [`pop_mut`](Self::pop_mut). The natural bulk counterpart to
+    /// [`push_slice`](Self::push_slice). Respects evictions like `pop_mut`,
+    /// so items the producer already spilled are skipped.
+    #[inline]
+    pub fn pop_slice(&mut self, out: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        let (head, len) = self.occupied_region();
+        let count = len.min(out.len());
+        if count == 0 {
+            return 0;
+        }
+        let head_idx = head & (N - 1);
+
+        // Safety: see `as_slices` -- `[head_idx, head_idx+count)` (wrapping)
+        // is within the occupied region, so every source byte is initialized.
+        unsafe {
+            let src = self.buffer.as_ptr().cast::<T>();
+            if head_idx + count <= N {
+                core::ptr::copy_nonoverlapping(src.add(head_idx), out.as_mut_ptr(), count);
+            } else {
+                let first_len = N - head_idx;
+                core::ptr::copy_nonoverlapping(src.add(head_idx), out.as_mut_ptr(), first_len);
+                core::ptr::copy_nonoverlapping(
+                    src,
+                    out.as_mut_ptr().add(first_len),
+                    count - first_len,
+                );
+            }
+        }
+
+        let new_head = head.wrapping_add(count);
+        self.head.store_mut(new_head);
+        #[cfg(feature = "atomics")]
+        self.evict_head.store_mut(new_head);
+        count
+    }
+
+    /// Pre-populate every slot via [`Recycle::new_element`].
+    ///
+    /// Required before using [`push_ref`](Self::push_ref) or
+    /// [`pop_ref`](Self::pop_ref): those hand out `&mut T`/`&T` straight
+    /// into slot storage and never call `assume_init`, so every slot must
+    /// already hold a valid `T`. Marks the ring as warmed so `Drop` knows to
+    /// drop all `N` slots, not just the `[head, tail)` window `push`/`pop`
+    /// track -- don't mix this with the move-semantics `push`/`pop`/`flush`
+    /// API on the same ring once you've called this.
+    ///
+    /// Calling this more than once on the same ring leaks whatever the
+    /// previous call wrote (it unconditionally overwrites every slot without
+    /// dropping the old value) -- call it exactly once, right after
+    /// construction.
+    pub fn warm_with<R: Recycle<T>>(&mut self, recycle: &R) {
+        for slot in &mut self.buffer {
+            unsafe { (*slot.data.get()).write(recycle.new_element()) };
+        }
+        self.warmed = true;
+    }
+
+    /// Reserve the next write slot for in-place construction, avoiding a
+    /// move for large/heap-backed `T`.
+    ///
+    /// `recycle.recycle()` is always run on the slot before it's handed
+    /// back -- whether it's being reused straight out of a `pop_ref` (the
+    /// steady-state SPSC pattern this method exists for) or evicted because
+    /// the ring is full -- so the returned guard always derefs over a
+    /// freshly-reset value, never several cycles' worth of stale data.
+    /// The write commits (advancing `tail`) when the guard drops.
+    ///
+    /// Every slot must already hold a valid `T` (see
+    /// [`warm_with`](Self::warm_with)) -- this method never initializes a
+    /// slot itself.
+    pub fn push_ref<'a, R: Recycle<T>>(&'a mut self, recycle: &R) -> PushRefGuard<'a, T, N, S> {
+        let tail = self.tail.load_mut();
+        let head = self.head.load_mut();
+
+        if tail.wrapping_sub(head) >= N {
+            // Full -- advance head past the slot we're about to overwrite
+            // (same slot as `idx` below: tail - head == N means they share
+            // the same index mod N).
+            self.head.store_mut(head.wrapping_add(1));
+        }
+
+        let idx = tail & (N - 1);
+        unsafe {
+            let slot = (*self.buffer[idx].data.get()).assume_init_mut();
+            recycle.recycle(slot);
+        }
+
+        PushRefGuard { idx, ring: self }
+    }
+
+    /// Borrow the oldest item in place, without moving it out of its slot.
+    ///
+    /// Returns a guard that derefs to `&T`; dropping the guard advances
+    /// `head`, logically removing the item. The slot keeps holding the
+    /// value until a later [`push_ref`](Self::push_ref) overwrites it --
+    /// the ring never reads it again.
+    ///
+    /// Every slot must already hold a valid `T` (see
+    /// [`warm_with`](Self::warm_with)).
+    pub fn pop_ref(&mut self) -> Option<PopRefGuard<'_, T, N, S>> {
+        let head = self.head.load_mut();
+        let tail = self.tail.load_mut();
+        if head == tail {
+            return None;
+        }
+        Some(PopRefGuard {
+            idx: head & (N - 1),
+            ring: self,
+        })
+    }
+
+    /// Block the current thread until an item is available, then pop it.
+    ///
+    /// Tries the lock-free [`pop`](Self::pop) fast path first and only
+    /// parks the thread on genuine emptiness, waking (with a short polling
+    /// fallback) whenever a producer publishes via [`push`](Self::push) or
+    /// [`push_mut`](Self::push_mut).
+    #[cfg(feature = "std")]
+    pub fn pop_blocking(&self) -> T {
+        loop {
+            if let Some(item) = self.pop() {
+                return item;
+            }
+            self.waiter.wait_briefly();
+        }
+    }
+
+    /// A `Stream` of popped items, for consumers on an async runtime.
+    ///
+    /// Never yields `None`: like a channel, the ring simply has no more
+    /// items *yet*, and polls again once a producer wakes it.
+    #[cfg(feature = "async")]
+    pub fn stream(&self) -> RingStream<'_, T, N, S> {
+        RingStream { ring: self }
+    }
+}
+
+/// `Stream` adapter returned by [`SpillRing::stream`].
+#[cfg(feature = "async")]
+pub struct RingStream<'a, T, const N: usize, S: Spout<T>> {
+    ring: &'a SpillRing<T, N, S>,
+}
+
+#[cfg(feature = "async")]
+impl<T, const N: usize, S: Spout<T>> futures_core::Stream for RingStream<'_, T, N, S> {
+    type Item = T;
+
+    fn poll_next(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Option<Self::Item>> {
+        if let Some(item) = self.ring.pop() {
+            return core::task::Poll::Ready(Some(item));
+        }
+        self.ring.waiter.register(cx.waker());
+        // Re-check after registering: a push between the first `pop` above
+        // and the registration would otherwise go unnoticed.
+        match self.ring.pop() {
+            Some(item) => core::task::Poll::Ready(Some(item)),
+            None => core::task::Poll::Pending,
+        }
+    }
+}
+
+/// Write guard returned by [`SpillRing::push_ref`].
+///
+/// Commits the write (advances `tail`) when dropped.
+pub struct PushRefGuard<'a, T, const N: usize, S: Spout<T>> {
+    ring: &'a mut SpillRing<T, N, S>,
+    idx: usize,
+}
+
+impl<T, const N: usize, S: Spout<T>> core::ops::Deref for PushRefGuard<'_, T, N, S> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { (*self.ring.buffer[self.idx].data.get()).assume_init_ref() }
+    }
+}
+
+impl<T, const N: usize, S: Spout<T>> core::ops::DerefMut for PushRefGuard<'_, T, N, S> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { (*self.ring.buffer[self.idx].data.get()).assume_init_mut() }
+    }
+}
+
+impl<T, const N: usize, S: Spout<T>> Drop for PushRefGuard<'_, T, N, S> {
+    fn drop(&mut self) {
+        let tail = self.ring.tail.load_mut();
+        self.ring.tail.store_mut(tail.wrapping_add(1));
+    }
+}
+
+/// Read guard returned by [`SpillRing::pop_ref`].
+///
+/// Advances `head` when dropped, logically removing the item.
+pub struct PopRefGuard<'a, T, const N: usize, S: Spout<T>> {
+    ring: &'a mut SpillRing<T, N, S>,
+    idx: usize,
+}
+
+impl<T, const N: usize, S: Spout<T>> core::ops::Deref for PopRefGuard<'_, T, N, S> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { (*self.ring.buffer[self.idx].data.get()).assume_init_ref() }
+    }
+}
+
+impl<T, const N: usize, S: Spout<T>> Drop for PopRefGuard<'_, T, N, S> {
+    fn drop(&mut self) {
+        let head = self.ring.head.load_mut();
+        self.ring.head.store_mut(head.wrapping_add(1));
+    }
 }
 
 /// Draining iterator over a SpillRing.
@@ -662,6 +1080,18 @@ impl<T, const N: usize, S: Spout<T>> Spout<T> for SpillRing<T, N, S> {
 
 impl<T, const N: usize, S: Spout<T>> Drop for SpillRing<T, N, S> {
     fn drop(&mut self) {
+        if self.warmed {
+            // warm_with() initialized every slot for push_ref/pop_ref, and
+            // they never uninitialize one (recycle() resets in place) --
+            // so all N slots hold a live T right now, not just the
+            // [head, tail) window push/pop track. Drop them directly;
+            // there's no spout to route them through (push_ref doesn't
+            // evict to the sink, it recycles in place).
+            for slot in &self.buffer {
+                unsafe { (*slot.data.get()).assume_init_drop() };
+            }
+            return;
+        }
         self.flush();
         self.sink.get_mut().flush();
     }
@@ -783,3 +1213,87 @@ mod layout_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod ref_tests {
+    use super::*;
+    use crate::Recycle;
+    use core::cell::Cell;
+
+    struct CountingRecycle {
+        resets: Cell<u32>,
+    }
+
+    impl Recycle<u64> for CountingRecycle {
+        fn new_element(&self) -> u64 {
+            0
+        }
+
+        fn recycle(&self, element: &mut u64) {
+            *element = 0;
+            self.resets.set(self.resets.get() + 1);
+        }
+    }
+
+    #[test]
+    fn push_ref_recycles_every_handed_out_slot() {
+        let mut ring: SpillRing<u64, 2> = SpillRing::new();
+        let recycle = CountingRecycle {
+            resets: Cell::new(0),
+        };
+        ring.warm_with(&recycle);
+
+        // push_ref always recycles the slot it's about to hand out, even on
+        // the very first fill (warm_with already gave every slot a valid
+        // element to recycle).
+        for i in 0..2u64 {
+            *ring.push_ref(&recycle) = i;
+        }
+        assert_eq!(recycle.resets.get(), 2);
+
+        // Steady state: alternate pop_ref/push_ref without ever filling the
+        // ring past N. Regression test for the chunk1-4 bug where recycle()
+        // only ran on the eviction branch, so this exact pattern never reset
+        // the slot push_ref handed back.
+        for i in 0..10u64 {
+            let popped = ring.pop_ref().unwrap();
+            drop(popped);
+            *ring.push_ref(&recycle) = i;
+        }
+        assert_eq!(recycle.resets.get(), 12);
+    }
+
+    struct DropCounter<'a>(&'a Cell<u32>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    struct CountingElementRecycle<'a>(&'a Cell<u32>);
+
+    impl<'a> Recycle<DropCounter<'a>> for CountingElementRecycle<'a> {
+        fn new_element(&self) -> DropCounter<'a> {
+            DropCounter(self.0)
+        }
+
+        fn recycle(&self, _element: &mut DropCounter<'a>) {}
+    }
+
+    #[test]
+    fn dropping_a_warmed_ring_drops_every_slot() {
+        // Regression test: warm_with() used to initialize every slot, but
+        // Drop only drained the logical [head, tail) window (the push/pop
+        // move-semantics model), leaking every slot warm_with touched that
+        // never got folded into that window -- the normal case for
+        // push_ref/pop_ref's partial-fill steady state.
+        let drops = Cell::new(0u32);
+        let recycle = CountingElementRecycle(&drops);
+        let mut ring: SpillRing<DropCounter<'_>, 4> = SpillRing::new();
+        ring.warm_with(&recycle);
+
+        drop(ring);
+        assert_eq!(drops.get(), 4, "every warm-initialized slot must be dropped");
+    }
+}
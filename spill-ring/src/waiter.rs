@@ -0,0 +1,91 @@
+//! Blocking/async wakeup registration for [`crate::SpillRing`].
+//!
+//! Lets a consumer park (or, under `async`, register a [`Waker`]) instead of
+//! busy-polling an empty ring, while producers stay on the lock-free
+//! `push`/`push_mut` fast path.
+
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+#[cfg(feature = "async")]
+use core::task::Waker;
+
+/// How long [`Waiter::wait_briefly`] parks before re-checking.
+///
+/// A notification that lands between the caller's emptiness check and the
+/// `Condvar::wait` call would otherwise be missed entirely (the state the
+/// emptiness check reads isn't protected by `lock`); bounding the wait
+/// caps the cost of that race at one timeout instead of an indefinite hang.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+pub(crate) struct Waiter {
+    lock: Mutex<()>,
+    condvar: Condvar,
+    #[cfg(feature = "async")]
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Waiter {
+    pub(crate) const fn new() -> Self {
+        Self {
+            lock: Mutex::new(()),
+            condvar: Condvar::new(),
+            #[cfg(feature = "async")]
+            waker: Mutex::new(None),
+        }
+    }
+
+    /// Wake any blocked or registered consumer. Called by producers after
+    /// publishing a new `tail`.
+    pub(crate) fn notify(&self) {
+        self.condvar.notify_all();
+        #[cfg(feature = "async")]
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    /// Park the current thread until notified, or [`POLL_INTERVAL`] elapses.
+    pub(crate) fn wait_briefly(&self) {
+        let guard = self.lock.lock().unwrap();
+        let _ = self.condvar.wait_timeout(guard, POLL_INTERVAL);
+    }
+
+    /// Register `waker` to be woken on the next [`notify`](Self::notify).
+    #[cfg(feature = "async")]
+    pub(crate) fn register(&self, waker: &Waker) {
+        *self.waker.lock().unwrap() = Some(waker.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Instant;
+
+    #[test]
+    fn notify_wakes_a_waiting_thread_before_the_poll_interval() {
+        let waiter = Arc::new(Waiter::new());
+        let waiting = Arc::clone(&waiter);
+
+        let handle = thread::spawn(move || {
+            let start = Instant::now();
+            waiting.wait_briefly();
+            start.elapsed()
+        });
+
+        // Give the spawned thread a head start to reach `wait_briefly`
+        // before we notify. A notify that lands first wakes nobody, and the
+        // thread then waits out the full POLL_INTERVAL.
+        thread::sleep(Duration::from_millis(1));
+        waiter.notify();
+
+        let elapsed = handle.join().unwrap();
+        assert!(
+            elapsed < POLL_INTERVAL,
+            "expected notify to wake the thread well before POLL_INTERVAL, took {elapsed:?}"
+        );
+    }
+}
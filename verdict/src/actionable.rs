@@ -1,5 +1,7 @@
 //! The Actionable trait for semantic error classification.
 
+use core::time::Duration;
+
 use crate::ErrorStatusValue;
 
 /// Errors that provide semantic information for programmatic handling.
@@ -34,6 +36,17 @@ pub trait Actionable {
     fn is_retryable(&self) -> bool {
         self.status_value().is_retryable()
     }
+
+    /// A minimum wait before the next attempt, if the error can advise one
+    /// (e.g. an HTTP 429 response's `Retry-After` header).
+    ///
+    /// Retry drivers should sleep for at least this long even if their own
+    /// computed backoff would be shorter, letting servers pace clients
+    /// explicitly. Returns `None` when the error gives no hint.
+    #[inline]
+    fn retry_after(&self) -> Option<Duration> {
+        None
+    }
 }
 
 // Blanket impl for references
@@ -42,6 +55,11 @@ impl<T: Actionable + ?Sized> Actionable for &T {
     fn status_value(&self) -> ErrorStatusValue {
         (**self).status_value()
     }
+
+    #[inline]
+    fn retry_after(&self) -> Option<Duration> {
+        (**self).retry_after()
+    }
 }
 
 // Blanket impl for Box (requires alloc)
@@ -51,4 +69,9 @@ impl<T: Actionable + ?Sized> Actionable for alloc::boxed::Box<T> {
     fn status_value(&self) -> ErrorStatusValue {
         (**self).status_value()
     }
+
+    #[inline]
+    fn retry_after(&self) -> Option<Duration> {
+        (**self).retry_after()
+    }
 }
@@ -0,0 +1,319 @@
+//! Narrow-pointer boxed `Actionable` error, analogous to `anyhow::Error`.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::any::TypeId;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+use core::time::Duration;
+use std::error::Error as StdError;
+
+use crate::{Actionable, ErrorStatusValue};
+
+#[repr(C)]
+struct ErrorImpl<E> {
+    vtable: &'static VTable,
+    error: E,
+}
+
+struct VTable {
+    object_drop: unsafe fn(NonNull<ErrorImpl<()>>),
+    object_ref: unsafe fn(&ErrorImpl<()>) -> &(dyn StdError + Send + Sync + 'static),
+    object_status: unsafe fn(&ErrorImpl<()>) -> ErrorStatusValue,
+    object_retry_after: unsafe fn(&ErrorImpl<()>) -> Option<Duration>,
+    type_id: fn() -> TypeId,
+}
+
+unsafe fn object_drop<E>(e: NonNull<ErrorImpl<()>>) {
+    // Safety: `e` was allocated as `Box<ErrorImpl<E>>` by `Verdict::new::<E>`.
+    // Casting back recovers the real size/align/drop glue for `E`.
+    drop(unsafe { Box::from_raw(e.cast::<ErrorImpl<E>>().as_ptr()) });
+}
+
+unsafe fn object_ref<E: StdError + Send + Sync + 'static>(
+    e: &ErrorImpl<()>,
+) -> &(dyn StdError + Send + Sync + 'static) {
+    // Safety: see `object_drop`.
+    let e = unsafe { &*(core::ptr::from_ref(e).cast::<ErrorImpl<E>>()) };
+    &e.error
+}
+
+unsafe fn object_status<E: Actionable>(e: &ErrorImpl<()>) -> ErrorStatusValue {
+    // Safety: see `object_drop`.
+    let e = unsafe { &*(core::ptr::from_ref(e).cast::<ErrorImpl<E>>()) };
+    e.error.status_value()
+}
+
+unsafe fn object_retry_after<E: Actionable>(e: &ErrorImpl<()>) -> Option<Duration> {
+    // Safety: see `object_drop`.
+    let e = unsafe { &*(core::ptr::from_ref(e).cast::<ErrorImpl<E>>()) };
+    e.error.retry_after()
+}
+
+fn type_id_of<E: 'static>() -> TypeId {
+    TypeId::of::<E>()
+}
+
+/// A heap-allocated, type-erased [`Actionable`] error.
+///
+/// Unlike a plain `Box<dyn Error>` (a two-word fat pointer), `Verdict` packs
+/// the vtable and the error value into one heap allocation and stores a
+/// single thin pointer to it. Classification survives the type erasure: you
+/// can shove heterogeneous actionable errors into one `Verdict`, still ask
+/// [`is_retryable`](Actionable::is_retryable), and later recover the
+/// concrete type with [`downcast`](Self::downcast).
+pub struct Verdict {
+    inner: NonNull<ErrorImpl<()>>,
+    _marker: PhantomData<Box<ErrorImpl<()>>>,
+}
+
+// Safety: `Verdict` owns its boxed `ErrorImpl<E>` exclusively, and `E: Send
+// + Sync` is required to construct one.
+unsafe impl Send for Verdict {}
+unsafe impl Sync for Verdict {}
+
+impl Verdict {
+    /// Box up an actionable error, erasing its concrete type.
+    pub fn new<E>(error: E) -> Self
+    where
+        E: Actionable + StdError + Send + Sync + 'static,
+    {
+        let vtable = &VTable {
+            object_drop: object_drop::<E>,
+            object_ref: object_ref::<E>,
+            object_status: object_status::<E>,
+            object_retry_after: object_retry_after::<E>,
+            type_id: type_id_of::<E>,
+        };
+        let boxed = Box::new(ErrorImpl { vtable, error });
+        let inner = NonNull::from(Box::leak(boxed)).cast::<ErrorImpl<()>>();
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    fn vtable(&self) -> &'static VTable {
+        unsafe { self.inner.as_ref().vtable }
+    }
+
+    fn as_dyn_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+        unsafe { (self.vtable().object_ref)(self.inner.as_ref()) }
+    }
+
+    /// Attach a display message as additional context.
+    ///
+    /// The inner error's status is preserved: added context should never
+    /// silently flip a retryable error to permanent.
+    #[must_use]
+    pub fn context(self, message: impl Into<String>) -> Self {
+        Verdict::new(WithContext {
+            message: message.into(),
+            inner: self,
+        })
+    }
+
+    /// Whether the erased error is of type `T`.
+    #[must_use]
+    pub fn is<T: 'static>(&self) -> bool {
+        (self.vtable().type_id)() == TypeId::of::<T>()
+    }
+
+    /// Attempt to downcast to a reference of the concrete error type.
+    #[must_use]
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        if self.is::<T>() {
+            Some(unsafe { &self.inner.cast::<ErrorImpl<T>>().as_ref().error })
+        } else {
+            None
+        }
+    }
+
+    /// Attempt to downcast into the concrete owned error type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` unchanged if the erased error is not of type `T`.
+    pub fn downcast<T: 'static>(self) -> Result<T, Self> {
+        if self.is::<T>() {
+            let inner = self.inner;
+            core::mem::forget(self);
+            let typed = unsafe { Box::from_raw(inner.cast::<ErrorImpl<T>>().as_ptr()) };
+            Ok(typed.error)
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Actionable for Verdict {
+    #[inline]
+    fn status_value(&self) -> ErrorStatusValue {
+        unsafe { (self.vtable().object_status)(self.inner.as_ref()) }
+    }
+
+    #[inline]
+    fn retry_after(&self) -> Option<Duration> {
+        unsafe { (self.vtable().object_retry_after)(self.inner.as_ref()) }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_dyn_error(), f)
+    }
+}
+
+impl fmt::Debug for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_dyn_error(), f)
+    }
+}
+
+impl StdError for Verdict {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.as_dyn_error().source()
+    }
+}
+
+impl Drop for Verdict {
+    fn drop(&mut self) {
+        unsafe { (self.vtable().object_drop)(self.inner) }
+    }
+}
+
+/// Wraps a [`Verdict`] with an additional display message, forwarding
+/// [`Actionable`] and [`Error::source`](StdError::source) to the inner error.
+struct WithContext {
+    message: String,
+    inner: Verdict,
+}
+
+impl fmt::Display for WithContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.message, self.inner)
+    }
+}
+
+impl fmt::Debug for WithContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {:?}", self.message, self.inner)
+    }
+}
+
+impl StdError for WithContext {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+impl Actionable for WithContext {
+    #[inline]
+    fn status_value(&self) -> ErrorStatusValue {
+        self.inner.status_value()
+    }
+
+    #[inline]
+    fn retry_after(&self) -> Option<Duration> {
+        self.inner.retry_after()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Debug)]
+    struct CountingError {
+        message: &'static str,
+        drops: Arc<AtomicU32>,
+    }
+
+    impl Drop for CountingError {
+        fn drop(&mut self) {
+            self.drops.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl fmt::Display for CountingError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.message)
+        }
+    }
+
+    impl StdError for CountingError {}
+
+    impl Actionable for CountingError {
+        fn status_value(&self) -> ErrorStatusValue {
+            ErrorStatusValue::Temporary
+        }
+    }
+
+    #[derive(Debug)]
+    struct OtherError;
+
+    impl fmt::Display for OtherError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("other")
+        }
+    }
+
+    impl StdError for OtherError {}
+
+    impl Actionable for OtherError {
+        fn status_value(&self) -> ErrorStatusValue {
+            ErrorStatusValue::Permanent
+        }
+    }
+
+    #[test]
+    fn downcast_ref_succeeds_for_the_concrete_type_and_fails_for_others() {
+        let drops = Arc::new(AtomicU32::new(0));
+        let verdict = Verdict::new(CountingError {
+            message: "boom",
+            drops: Arc::clone(&drops),
+        });
+
+        assert!(verdict.is::<CountingError>());
+        assert!(!verdict.is::<OtherError>());
+        assert_eq!(
+            verdict.downcast_ref::<CountingError>().unwrap().message,
+            "boom"
+        );
+        assert!(verdict.downcast_ref::<OtherError>().is_none());
+        assert_eq!(verdict.status_value(), ErrorStatusValue::Temporary);
+
+        drop(verdict);
+        assert_eq!(drops.load(Ordering::SeqCst), 1, "Drop must run exactly once");
+    }
+
+    #[test]
+    fn downcast_recovers_the_owned_value_or_hands_verdict_back_unchanged() {
+        let drops = Arc::new(AtomicU32::new(0));
+        let verdict = Verdict::new(CountingError {
+            message: "boom",
+            drops: Arc::clone(&drops),
+        });
+
+        let verdict = match verdict.downcast::<OtherError>() {
+            Ok(_) => panic!("downcast to the wrong type must not succeed"),
+            Err(verdict) => verdict,
+        };
+
+        let recovered = verdict
+            .downcast::<CountingError>()
+            .expect("downcast to the right type must succeed");
+        assert_eq!(recovered.message, "boom");
+        assert_eq!(
+            drops.load(Ordering::SeqCst),
+            0,
+            "owned downcast must move the value out, not drop it"
+        );
+
+        drop(recovered);
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,153 @@
+//! Classification by walking a `std::error::Error` source chain.
+
+use std::error::Error;
+
+use crate::{Actionable, ErrorStatusValue};
+
+/// Classify `err` by walking its `source()` chain, returning the status of
+/// the first link that downcasts to `A`.
+///
+/// This is the common real-world case where a retryable low-level error
+/// (e.g. `std::io::Error`) is wrapped by several higher-level error types
+/// and the top-level error alone can't tell you whether to retry. If no
+/// link downcasts to `A`, falls back to `Permanent`.
+#[must_use]
+pub fn classify_chain<A: Actionable + Error + 'static>(
+    err: &(dyn Error + 'static),
+) -> ErrorStatusValue {
+    let mut cur: Option<&(dyn Error + 'static)> = Some(err);
+    while let Some(e) = cur {
+        if let Some(actionable) = e.downcast_ref::<A>() {
+            return actionable.status_value();
+        }
+        cur = e.source();
+    }
+    ErrorStatusValue::Permanent
+}
+
+/// A classification function for one concrete, registered error type.
+type Classifier = fn(&(dyn Error + 'static)) -> Option<ErrorStatusValue>;
+
+/// A registry of concrete `Actionable` error types to try when walking a
+/// `source()` chain.
+///
+/// Use this over [`classify_chain`] when more than one wrapped error type
+/// in the chain might be `Actionable` (e.g. a retryable I/O error wrapped by
+/// one of several possible higher-level errors).
+#[derive(Default)]
+pub struct ChainClassifier {
+    classifiers: alloc::vec::Vec<Classifier>,
+}
+
+impl ChainClassifier {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a candidate error type to try at each link of the chain.
+    #[must_use]
+    pub fn register<A: Actionable + Error + 'static>(mut self) -> Self {
+        self.classifiers
+            .push(|e| e.downcast_ref::<A>().map(Actionable::status_value));
+        self
+    }
+
+    /// Classify `err` by walking its `source()` chain, trying every
+    /// registered type at each link in registration order. Falls back to
+    /// `Permanent` if no link matches any registered type.
+    #[must_use]
+    pub fn classify_chain(&self, err: &(dyn Error + 'static)) -> ErrorStatusValue {
+        let mut cur: Option<&(dyn Error + 'static)> = Some(err);
+        while let Some(e) = cur {
+            for classifier in &self.classifiers {
+                if let Some(status) = classifier(e) {
+                    return status;
+                }
+            }
+            cur = e.source();
+        }
+        ErrorStatusValue::Permanent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt;
+
+    use crate::Actionable;
+
+    #[derive(Debug)]
+    struct RetryableIoError;
+
+    impl fmt::Display for RetryableIoError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("connection reset")
+        }
+    }
+
+    impl Error for RetryableIoError {}
+
+    impl Actionable for RetryableIoError {
+        fn status_value(&self) -> ErrorStatusValue {
+            ErrorStatusValue::Temporary
+        }
+    }
+
+    #[derive(Debug)]
+    struct WrapperError(RetryableIoError);
+
+    impl fmt::Display for WrapperError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "request failed: {}", self.0)
+        }
+    }
+
+    impl Error for WrapperError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn classify_chain_finds_an_actionable_link_below_the_top_level_error() {
+        let err = WrapperError(RetryableIoError);
+        let status = classify_chain::<RetryableIoError>(&err);
+        assert_eq!(status, ErrorStatusValue::Temporary);
+    }
+
+    #[test]
+    fn classify_chain_falls_back_to_permanent_when_nothing_matches() {
+        #[derive(Debug)]
+        struct Unrelated;
+
+        impl fmt::Display for Unrelated {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("unrelated")
+            }
+        }
+
+        impl Error for Unrelated {}
+
+        let status = classify_chain::<RetryableIoError>(&Unrelated);
+        assert_eq!(status, ErrorStatusValue::Permanent);
+    }
+
+    #[test]
+    fn chain_classifier_matches_only_registered_types() {
+        let classifier = ChainClassifier::new().register::<RetryableIoError>();
+        let err = WrapperError(RetryableIoError);
+        assert_eq!(
+            classifier.classify_chain(&err),
+            ErrorStatusValue::Temporary
+        );
+
+        let unregistered = ChainClassifier::new();
+        assert_eq!(
+            unregistered.classify_chain(&err),
+            ErrorStatusValue::Permanent
+        );
+    }
+}
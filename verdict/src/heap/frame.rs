@@ -4,6 +4,11 @@ use alloc::borrow::Cow;
 use alloc::string::String;
 use core::fmt;
 
+#[cfg(feature = "std")]
+use alloc::vec;
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+
 /// A single frame of error context.
 ///
 /// Frames capture where context was added (file, line, column) and a message
@@ -92,6 +97,189 @@ impl Frame {
     }
 }
 
+#[cfg(feature = "std")]
+fn write_var_int(w: &mut impl Write, mut value: usize) -> io::Result<usize> {
+    let mut written = 0;
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        w.write_all(&[byte])?;
+        written += 1;
+        if value == 0 {
+            return Ok(written);
+        }
+    }
+}
+
+/// Decode a var_int whose first byte has already been read as `first_byte`
+/// (e.g. after peeking it to distinguish a clean EOF from a mid-record one).
+/// Returns the decoded value and the total number of bytes making up the
+/// var_int, including `first_byte`.
+#[cfg(feature = "std")]
+fn read_var_int_continuing(r: &mut impl Read, first_byte: u8) -> io::Result<(usize, usize)> {
+    let mut value = (first_byte & 0x7f) as usize;
+    let mut shift = 7u32;
+    let mut consumed = 1;
+    let mut byte = first_byte;
+    while byte & 0x80 != 0 {
+        let mut buf = [0u8; 1];
+        r.read_exact(&mut buf)?;
+        consumed += 1;
+        byte = buf[0];
+        value |= ((byte & 0x7f) as usize) << shift;
+        shift += 7;
+    }
+    Ok((value, consumed))
+}
+
+/// Read one byte, or `Ok(None)` on a clean EOF (no bytes available at all).
+/// Retries on [`io::ErrorKind::Interrupted`] like `read_exact` does, unlike a
+/// bare `Read::read` call.
+#[cfg(feature = "std")]
+fn read_one_byte_or_eof(r: &mut impl Read) -> io::Result<Option<u8>> {
+    let mut byte = [0u8; 1];
+    loop {
+        match r.read(&mut byte) {
+            Ok(0) => return Ok(None),
+            Ok(_) => return Ok(Some(byte[0])),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn read_var_int(r: &mut impl Read) -> io::Result<(usize, usize)> {
+    let mut first = [0u8; 1];
+    r.read_exact(&mut first)?;
+    read_var_int_continuing(r, first[0])
+}
+
+#[cfg(feature = "std")]
+fn write_str(w: &mut impl Write, s: &str) -> io::Result<()> {
+    write_var_int(w, s.len())?;
+    w.write_all(s.as_bytes())
+}
+
+#[cfg(feature = "std")]
+fn read_string(r: &mut impl Read) -> io::Result<String> {
+    let (len, _) = read_var_int(r)?;
+    let mut buf = vec![0u8; len];
+    r.read_exact(&mut buf)?;
+    String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Decode a frame's fields (everything after the outer length prefix) from
+/// `r`. Shared by [`Frame::read_from`] and [`FrameReader`] so the field
+/// layout only has to agree with [`Frame::write_to`] in one place.
+#[cfg(feature = "std")]
+fn decode_body(r: &mut impl Read) -> io::Result<Frame> {
+    let mut line_bytes = [0u8; 4];
+    r.read_exact(&mut line_bytes)?;
+    let mut column_bytes = [0u8; 4];
+    r.read_exact(&mut column_bytes)?;
+
+    let file = read_string(r)?;
+    let message = read_string(r)?;
+
+    Ok(Frame {
+        file: Cow::Owned(file),
+        line: u32::from_le_bytes(line_bytes),
+        column: u32::from_le_bytes(column_bytes),
+        message,
+    })
+}
+
+#[cfg(feature = "std")]
+impl Frame {
+    /// Serialize this frame as a length-delimited record: a var_int byte
+    /// count for everything that follows (high bit of each length byte is a
+    /// continuation flag), then `line` and `column` as 4-byte little-endian
+    /// integers, then `file` and `message` as var_int-length-prefixed UTF-8
+    /// strings. The outer length prefix lets a reader skip a corrupted or
+    /// unrecognized record by byte count instead of desyncing the rest of
+    /// the stream. Returns the number of bytes written, so callers can
+    /// compose this into a larger framed protocol without re-measuring.
+    ///
+    /// This is a separate, simpler wire format from the one derived by
+    /// `#[derive(bytecast::DeriveToBytes, bytecast::DeriveFromBytes)]` on
+    /// this struct (under the `bytecast` feature): it streams through
+    /// `io::Read`/`io::Write` instead of a single in-memory slice, and the
+    /// two are not byte-for-byte interchangeable.
+    pub fn write_to(&self, w: &mut impl Write) -> io::Result<usize> {
+        let mut body: alloc::vec::Vec<u8> = alloc::vec::Vec::new();
+        body.write_all(&self.line.to_le_bytes())?;
+        body.write_all(&self.column.to_le_bytes())?;
+        write_str(&mut body, &self.file)?;
+        write_str(&mut body, &self.message)?;
+
+        let prefix_len = write_var_int(w, body.len())?;
+        w.write_all(&body)?;
+        Ok(prefix_len + body.len())
+    }
+
+    /// Deserialize a frame written by [`write_to`](Self::write_to),
+    /// returning it along with the number of bytes consumed.
+    pub fn read_from(r: &mut impl Read) -> io::Result<(Self, usize)> {
+        let (len, prefix_len) = read_var_int(r)?;
+        let mut body = vec![0u8; len];
+        r.read_exact(&mut body)?;
+        let frame = decode_body(&mut &body[..])?;
+        Ok((frame, prefix_len + len))
+    }
+}
+
+/// Decodes a stream of [`Frame`]s written back-to-back by
+/// [`Frame::write_to`], e.g. an overflow log spilled to a file.
+///
+/// A clean end of stream (no bytes available before the start of the next
+/// record) ends iteration; an EOF partway through a record is reported as
+/// an error instead of silently truncating the last frame.
+#[cfg(feature = "std")]
+pub struct FrameReader<R> {
+    reader: R,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> FrameReader<R> {
+    /// Wrap `reader` to decode a stream of frames from it.
+    #[must_use]
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    /// Consume the reader, returning the inner value.
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> Iterator for FrameReader<R> {
+    type Item = io::Result<Frame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Peek one byte of the length prefix to distinguish a clean EOF
+        // (no bytes before the next record) from an EOF partway through one.
+        let first = match read_one_byte_or_eof(&mut self.reader) {
+            Ok(None) => return None,
+            Ok(Some(byte)) => byte,
+            Err(e) => return Some(Err(e)),
+        };
+
+        Some((|| {
+            let (len, _) = read_var_int_continuing(&mut self.reader, first)?;
+            let mut body = vec![0u8; len];
+            self.reader.read_exact(&mut body)?;
+            decode_body(&mut &body[..])
+        })())
+    }
+}
+
 impl fmt::Display for Frame {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.file == "<unknown>" {
@@ -11,9 +11,16 @@ mod log_record;
 mod retry;
 mod sinks;
 
+#[cfg(feature = "std")]
+mod boxed;
+#[cfg(feature = "std")]
+mod chain;
+
 pub use contextualized::Contextualized;
 pub use ext::{ContextExt, IntoContextualized, OptionExt, ResultExt};
 pub use frame::Frame;
+#[cfg(feature = "std")]
+pub use frame::FrameReader;
 pub use log_record::{FrameRecord, LogRecord};
 pub use retry::{RetryOutcome, with_retry};
 pub use sinks::{CountingSpout, FrameFormatter, LogSpout, TeeSpout};
@@ -21,9 +28,18 @@ pub use sinks::{CountingSpout, FrameFormatter, LogSpout, TeeSpout};
 #[cfg(feature = "std")]
 pub use retry::{exponential_backoff, with_retry_delay};
 
+#[cfg(feature = "std")]
+pub use boxed::Verdict;
+
+#[cfg(feature = "std")]
+pub use chain::{ChainClassifier, classify_chain};
+
 #[cfg(feature = "std")]
 pub use sinks::StderrSpout;
 
+#[cfg(any(feature = "std", feature = "core_io"))]
+pub use sinks::{BufMode, BufSpout, WriteSpout};
+
 // Re-export spout types needed by users of alloc types
 pub use spout::{CollectSpout, DropSpout, Spout};
 
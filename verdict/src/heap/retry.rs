@@ -0,0 +1,275 @@
+//! Retry driver for [`Actionable`] errors.
+//!
+//! Retries a fallible operation while its error classifies as
+//! [`ErrorStatusValue::Temporary`], giving up immediately on
+//! [`ErrorStatusValue::Permanent`]. Once `max_retries` is exhausted the last
+//! error is returned as-is; semantically it is now `Persistent`, matching the
+//! `Temporary -> Persistent` typestate transition in [`crate::status`].
+
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+
+use crate::{Actionable, ErrorStatusValue};
+
+/// Jitter strategy applied to a computed backoff delay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jitter {
+    /// No jitter: always sleep the full computed backoff.
+    None,
+    /// AWS "full jitter": sleep a uniformly random duration in `[0, backoff]`.
+    Full,
+}
+
+/// Exponential backoff policy with jitter.
+///
+/// The delay for zero-indexed attempt `n` is `min(cap, base * 2^n)`, then
+/// jittered according to [`Jitter`].
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Base delay used for the first retry.
+    pub base: Duration,
+    /// Upper bound on the computed delay, applied before jitter.
+    pub cap: Duration,
+    /// Maximum number of retries before giving up.
+    pub max_retries: u32,
+    /// Jitter strategy.
+    pub jitter: Jitter,
+}
+
+impl RetryPolicy {
+    /// Create a policy with full jitter (the recommended default).
+    #[must_use]
+    pub const fn new(base: Duration, cap: Duration, max_retries: u32) -> Self {
+        Self {
+            base,
+            cap,
+            max_retries,
+            jitter: Jitter::Full,
+        }
+    }
+
+    /// Override the jitter strategy.
+    #[must_use]
+    pub const fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Compute the backoff delay for the given zero-indexed attempt.
+    #[must_use]
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        let scaled = self.base.saturating_mul(factor);
+        let bounded = if scaled > self.cap { self.cap } else { scaled };
+        match self.jitter {
+            Jitter::None => bounded,
+            Jitter::Full => full_jitter(bounded),
+        }
+    }
+}
+
+/// AWS-style "full jitter": a uniformly random duration in `[0, max]`.
+fn full_jitter(max: Duration) -> Duration {
+    if max.is_zero() {
+        return max;
+    }
+    let max_nanos = max.as_nanos().min(u64::MAX as u128) as u64;
+    Duration::from_nanos(next_rand_u64() % (max_nanos + 1))
+}
+
+// Process-wide counter stepped by a Weyl constant, each value mixed with the
+// xorshift64* output function. Not cryptographic, just enough spread to
+// avoid every retrying thread sleeping in lockstep ("thundering herd").
+static RNG_STATE: AtomicU64 = AtomicU64::new(0x9E37_79B9_7F4A_7C15);
+
+fn next_rand_u64() -> u64 {
+    let mut x = RNG_STATE.fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed);
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+}
+
+/// Outcome of a retry loop.
+#[derive(Debug, Clone)]
+pub enum RetryOutcome<T, E> {
+    /// The operation succeeded, possibly after retries.
+    Success(T),
+    /// Retries were exhausted, or the error was immediately `Permanent`.
+    Exhausted(E),
+}
+
+impl<T, E> RetryOutcome<T, E> {
+    /// Convert into a `Result`, discarding the retry-exhaustion distinction.
+    pub fn into_result(self) -> Result<T, E> {
+        match self {
+            Self::Success(v) => Ok(v),
+            Self::Exhausted(e) => Err(e),
+        }
+    }
+}
+
+/// Drive `op` according to `policy`, calling `sleep` with each computed
+/// delay between attempts.
+///
+/// This is the `no_std`-friendly core: `sleep` can be a busy-wait, a timer
+/// callback, or anything else that blocks for (at least) the given duration,
+/// so callers can plug in their own timer instead of depending on `std`.
+///
+/// Retries while the error's [`Actionable::status_value`] is `Temporary`,
+/// giving up immediately on `Permanent`. If the error advertises a
+/// [`Actionable::retry_after`] hint, the delay is `max(computed_backoff,
+/// retry_after)` so servers can pace clients explicitly.
+pub fn with_retry<T, E, F, D>(policy: &RetryPolicy, mut op: F, mut sleep: D) -> RetryOutcome<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+    E: Actionable,
+    D: FnMut(Duration),
+{
+    let mut attempt = 0u32;
+    loop {
+        match op() {
+            Ok(value) => return RetryOutcome::Success(value),
+            Err(err) => {
+                if err.status_value() != ErrorStatusValue::Temporary || attempt >= policy.max_retries
+                {
+                    return RetryOutcome::Exhausted(err);
+                }
+                let backoff = policy.delay_for(attempt);
+                let delay = match err.retry_after() {
+                    Some(hint) if hint > backoff => hint,
+                    _ => backoff,
+                };
+                sleep(delay);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Default exponential backoff policy: 100ms base, 30s cap, 5 retries, full jitter.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn exponential_backoff() -> RetryPolicy {
+    RetryPolicy::new(Duration::from_millis(100), Duration::from_secs(30), 5)
+}
+
+/// Blocking retry driver. Sleeps on the current thread between attempts via
+/// [`std::thread::sleep`].
+#[cfg(feature = "std")]
+pub fn with_retry_delay<T, E, F>(policy: &RetryPolicy, op: F) -> RetryOutcome<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+    E: Actionable,
+{
+    with_retry(policy, op, std::thread::sleep)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FlakyError {
+        status: ErrorStatusValue,
+        retry_after: Option<Duration>,
+    }
+
+    impl Actionable for FlakyError {
+        fn status_value(&self) -> ErrorStatusValue {
+            self.status
+        }
+
+        fn retry_after(&self) -> Option<Duration> {
+            self.retry_after
+        }
+    }
+
+    #[test]
+    fn with_retry_gives_up_immediately_on_a_permanent_error() {
+        let policy = RetryPolicy::new(Duration::ZERO, Duration::ZERO, 5);
+        let mut attempts = 0u32;
+        let outcome = with_retry::<(), _, _, _>(
+            &policy,
+            || {
+                attempts += 1;
+                Err(FlakyError {
+                    status: ErrorStatusValue::Permanent,
+                    retry_after: None,
+                })
+            },
+            |_| {},
+        );
+
+        assert_eq!(attempts, 1, "a Permanent error must not be retried");
+        assert!(matches!(outcome, RetryOutcome::Exhausted(_)));
+    }
+
+    #[test]
+    fn with_retry_retries_temporary_errors_until_success_or_exhaustion() {
+        let policy = RetryPolicy::new(Duration::ZERO, Duration::ZERO, 2);
+        let mut attempts = 0u32;
+        let outcome = with_retry(
+            &policy,
+            || {
+                attempts += 1;
+                if attempts <= 2 {
+                    Err(FlakyError {
+                        status: ErrorStatusValue::Temporary,
+                        retry_after: None,
+                    })
+                } else {
+                    Ok(attempts)
+                }
+            },
+            |_| {},
+        );
+
+        assert_eq!(attempts, 3);
+        assert!(matches!(outcome, RetryOutcome::Success(3)));
+    }
+
+    #[test]
+    fn with_retry_exhausts_after_max_retries_and_returns_the_last_error() {
+        let policy = RetryPolicy::new(Duration::ZERO, Duration::ZERO, 1);
+        let mut attempts = 0u32;
+        let outcome = with_retry::<(), _, _, _>(
+            &policy,
+            || {
+                attempts += 1;
+                Err(FlakyError {
+                    status: ErrorStatusValue::Temporary,
+                    retry_after: None,
+                })
+            },
+            |_| {},
+        );
+
+        assert_eq!(attempts, 2, "one initial attempt plus one retry");
+        assert!(matches!(outcome, RetryOutcome::Exhausted(_)));
+    }
+
+    #[test]
+    fn with_retry_honors_a_retry_after_hint_longer_than_the_computed_backoff() {
+        let policy = RetryPolicy::new(Duration::from_millis(1), Duration::from_secs(1), 1)
+            .with_jitter(Jitter::None);
+        let mut attempts = 0u32;
+        let mut delays = std::vec::Vec::new();
+        let _ = with_retry::<(), _, _, _>(
+            &policy,
+            || {
+                attempts += 1;
+                Err(FlakyError {
+                    status: ErrorStatusValue::Temporary,
+                    retry_after: Some(Duration::from_secs(10)),
+                })
+            },
+            |delay| delays.push(delay),
+        );
+
+        assert_eq!(
+            delays,
+            [Duration::from_secs(10)],
+            "retry_after must win when it exceeds the computed backoff"
+        );
+    }
+}
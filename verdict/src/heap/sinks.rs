@@ -8,6 +8,12 @@ use spout::Spout;
 
 use crate::Frame;
 
+#[cfg(feature = "std")]
+use std::io::Write;
+
+#[cfg(all(not(feature = "std"), feature = "core_io"))]
+use core_io::Write;
+
 /// A spout that formats frames to a string buffer.
 ///
 /// Useful for collecting overflow frames as formatted text.
@@ -120,30 +126,202 @@ impl<T> Spout<T> for &CountingSpout {
     }
 }
 
+/// A spout that writes formatted frames to any [`Write`] sink.
+///
+/// Generic over the writer so it works with `std::io::Write` (files,
+/// sockets, `Vec<u8>`, ...) under the `std` feature, or `core_io::Write`
+/// under `no_std` with the `core_io` feature. [`StderrSpout`] is a thin
+/// wrapper over `std::io::Stderr`.
+#[cfg(any(feature = "std", feature = "core_io"))]
+#[derive(Debug, Clone)]
+pub struct WriteSpout<W> {
+    writer: W,
+}
+
+#[cfg(any(feature = "std", feature = "core_io"))]
+impl<W: Write> WriteSpout<W> {
+    /// Wrap an existing writer.
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Borrow the inner writer.
+    #[must_use]
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Mutably borrow the inner writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consume the spout and return the inner writer.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core_io"))]
+impl<W: Write> Spout<Frame> for WriteSpout<W> {
+    fn send(&mut self, frame: Frame) {
+        let _ = writeln!(self.writer, "[verdict overflow] {frame}");
+    }
+
+    fn flush(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
 /// A spout that writes frames to stderr.
 ///
-/// Only available with the `std` feature.
+/// Only available with the `std` feature. This wraps [`WriteSpout`] rather
+/// than being a bare type alias for it: `std::io::Stderr` isn't `Clone`, so
+/// unlike the old unit-struct `StderrSpout`, this type does not implement
+/// `Clone`/`Copy` -- call [`StderrSpout::new`] again instead of cloning an
+/// existing one.
 #[cfg(feature = "std")]
-#[derive(Debug, Clone, Copy, Default)]
-pub struct StderrSpout;
+#[derive(Debug)]
+pub struct StderrSpout(WriteSpout<std::io::Stderr>);
 
 #[cfg(feature = "std")]
 impl StderrSpout {
     /// Create a new stderr spout.
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self(WriteSpout::new(std::io::stderr()))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for StderrSpout {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[cfg(feature = "std")]
 impl Spout<Frame> for StderrSpout {
     fn send(&mut self, frame: Frame) {
-        std::eprintln!("[verdict overflow] {}", frame);
+        self.0.send(frame);
+    }
+
+    fn flush(&mut self) {
+        self.0.flush();
+    }
+}
+
+/// How eagerly a [`BufSpout`] flushes its internal buffer to the writer.
+#[cfg(any(feature = "std", feature = "core_io"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BufMode {
+    /// Only flush when the buffer fills, [`flush()`](Spout::flush) is
+    /// called explicitly, or the spout is consumed or dropped.
+    #[default]
+    Full,
+    /// Flush after every frame.
+    LineBuffered,
+}
+
+/// Default buffer capacity, matching `std::io::BufWriter`.
+#[cfg(any(feature = "std", feature = "core_io"))]
+const DEFAULT_BUF_CAPACITY: usize = 8 * 1024;
+
+/// A spout that batches frame writes into an internal buffer before
+/// flushing to the wrapped writer, like [`std::io::BufWriter`].
+///
+/// `W` is a raw [`Write`], not a [`Spout<Frame>`] -- batching needs direct
+/// access to the byte stream underneath, which `Spout::send` doesn't
+/// expose, so `BufSpout` can't wrap [`WriteSpout`] the way [`TeeSpout`]
+/// wraps other spouts. Construct with [`line_buffered()`](Self::line_buffered)
+/// to flush after every frame instead of waiting for the buffer to fill --
+/// useful when overflow frames should show up in a terminal or log tail
+/// promptly rather than batched.
+#[cfg(any(feature = "std", feature = "core_io"))]
+#[derive(Debug)]
+pub struct BufSpout<W: Write> {
+    writer: W,
+    buffer: String,
+    capacity: usize,
+    mode: BufMode,
+}
+
+#[cfg(any(feature = "std", feature = "core_io"))]
+impl<W: Write> BufSpout<W> {
+    /// Wrap `writer` with the default (8 KiB, fully-buffered) settings.
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        Self::with_capacity(DEFAULT_BUF_CAPACITY, writer)
+    }
+
+    /// Wrap `writer` with a custom buffer capacity.
+    #[must_use]
+    pub fn with_capacity(capacity: usize, writer: W) -> Self {
+        Self {
+            writer,
+            buffer: String::with_capacity(capacity),
+            capacity,
+            mode: BufMode::Full,
+        }
+    }
+
+    /// Flush after every frame instead of waiting for the buffer to fill.
+    #[must_use]
+    pub fn line_buffered(mut self) -> Self {
+        self.mode = BufMode::LineBuffered;
+        self
+    }
+
+    /// Mutably borrow the inner writer. Buffered data is not flushed first.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consume the spout, flushing any buffered data, and return the inner writer.
+    pub fn into_inner(mut self) -> W {
+        self.flush_buffer();
+        let this = core::mem::ManuallyDrop::new(self);
+        // Safety: `this` is never dropped or used again, so each field is
+        // read out exactly once.
+        unsafe {
+            drop(core::ptr::read(&this.buffer));
+            core::ptr::read(&this.writer)
+        }
+    }
+
+    fn flush_buffer(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let _ = self.writer.write_all(self.buffer.as_bytes());
+        let _ = self.writer.flush();
+        self.buffer.clear();
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core_io"))]
+impl<W: Write> Spout<Frame> for BufSpout<W> {
+    fn send(&mut self, frame: Frame) {
+        let _ = writeln!(self.buffer, "[verdict overflow] {frame}");
+        if self.mode == BufMode::LineBuffered || self.buffer.len() >= self.capacity {
+            self.flush_buffer();
+        }
     }
 
     fn flush(&mut self) {
-        // stderr is typically unbuffered, but we could call std::io::stderr().flush()
+        self.flush_buffer();
+    }
+}
+
+#[cfg(any(feature = "std", feature = "core_io"))]
+impl<W> Drop for BufSpout<W>
+where
+    W: Write,
+{
+    fn drop(&mut self) {
+        self.flush_buffer();
     }
 }
 
@@ -8,6 +8,9 @@
 //! - `Permanent`: Never retryable (invalid input, not found, etc.)
 
 use core::fmt;
+use core::marker::PhantomData;
+
+use crate::Actionable;
 
 /// Runtime status value.
 #[non_exhaustive]
@@ -146,3 +149,154 @@ impl Terminal for Permanent {}
 pub trait NonTerminal: Status {}
 impl NonTerminal for Dynamic {}
 impl NonTerminal for Temporary {}
+
+// Typestate Wrapper
+
+/// Either of two values, used by [`Classified::resolve`] to return a
+/// statically-typed value without knowing in advance which branch it'll be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<L, R> {
+    /// The left variant.
+    Left(L),
+    /// The right variant.
+    Right(R),
+}
+
+/// A value tagged at compile time with its retry [`Status`].
+///
+/// Transitions are enforced by the type system rather than checked at
+/// runtime: only [`Temporary`] exposes [`exhaust`](Self::exhaust), only
+/// [`NonTerminal`] states expose [`retry`](Self::retry), and only
+/// [`Terminal`] states expose [`into_inner`](Self::into_inner). This makes
+/// it impossible to, say, call `retry` on a `Permanent` value -- the
+/// compiler rejects it rather than the check happening at runtime.
+#[derive(Debug, Clone, Copy)]
+pub struct Classified<T, S: Status> {
+    value: T,
+    _status: PhantomData<S>,
+}
+
+/// Reclassify a value as a different (statically chosen) status, without
+/// re-checking anything at runtime.
+fn reclassify<T, S: Status>(value: T) -> Classified<T, S> {
+    Classified {
+        value,
+        _status: PhantomData,
+    }
+}
+
+impl<T, S: Status> Classified<T, S> {
+    /// The status name, for debugging.
+    #[must_use]
+    pub fn status_name(&self) -> &'static str {
+        S::name()
+    }
+}
+
+impl<T> Classified<T, Dynamic> {
+    /// Wrap a value whose retry status isn't yet known at compile time.
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            _status: PhantomData,
+        }
+    }
+}
+
+impl<T: Actionable> Classified<T, Dynamic> {
+    /// Read `T`'s runtime [`ErrorStatusValue`] once and hand back a
+    /// statically-typed value, so downstream branches are checked at
+    /// compile time.
+    #[must_use]
+    pub fn resolve(self) -> Either<Classified<T, Temporary>, Classified<T, Permanent>> {
+        match self.value.status_value() {
+            ErrorStatusValue::Temporary => Either::Left(reclassify(self.value)),
+            ErrorStatusValue::Permanent | ErrorStatusValue::Persistent => {
+                Either::Right(reclassify(self.value))
+            }
+        }
+    }
+}
+
+impl<T> Classified<T, Temporary> {
+    /// Exhaust retries: transition from `Temporary` to `Persistent`.
+    #[must_use]
+    pub fn exhaust(self) -> Classified<T, Persistent> {
+        reclassify(self.value)
+    }
+}
+
+impl<T, S: NonTerminal> Classified<T, S> {
+    /// Record a new attempt's value. The result starts back at `Dynamic`
+    /// since the new attempt's status isn't known yet.
+    #[must_use]
+    pub fn retry<U>(self, next: U) -> Classified<U, Dynamic> {
+        Classified::new(next)
+    }
+}
+
+impl<T, S: Terminal> Classified<T, S> {
+    /// Consume a terminal classification, returning the inner value.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorStatusValue;
+
+    struct FlakyError(ErrorStatusValue);
+
+    impl Actionable for FlakyError {
+        fn status_value(&self) -> ErrorStatusValue {
+            self.0
+        }
+    }
+
+    #[test]
+    fn resolve_routes_temporary_and_permanent_to_the_matching_branch() {
+        let temporary = Classified::new(FlakyError(ErrorStatusValue::Temporary)).resolve();
+        assert!(matches!(temporary, Either::Left(_)));
+
+        let permanent = Classified::new(FlakyError(ErrorStatusValue::Permanent)).resolve();
+        assert!(matches!(permanent, Either::Right(_)));
+
+        let persistent = Classified::new(FlakyError(ErrorStatusValue::Persistent)).resolve();
+        assert!(matches!(persistent, Either::Right(_)));
+    }
+
+    #[test]
+    fn full_retry_cycle_ends_at_a_terminal_state() {
+        let dynamic = Classified::new(FlakyError(ErrorStatusValue::Temporary));
+        let Either::Left(temporary) = dynamic.resolve() else {
+            panic!("a Temporary error must resolve to the Left (Temporary) branch");
+        };
+        assert_eq!(temporary.status_name(), "Temporary");
+
+        let retried = temporary.retry(FlakyError(ErrorStatusValue::Permanent));
+        let Either::Right(permanent) = retried.resolve() else {
+            panic!("a Permanent error must resolve to the Right (Permanent) branch");
+        };
+        assert_eq!(permanent.status_name(), "Permanent");
+        assert!(matches!(permanent.into_inner().0, ErrorStatusValue::Permanent));
+    }
+
+    #[test]
+    fn exhaust_transitions_temporary_to_persistent() {
+        let temporary = Classified::new(FlakyError(ErrorStatusValue::Temporary));
+        let Either::Left(temporary) = temporary.resolve() else {
+            panic!("a Temporary error must resolve to the Left (Temporary) branch");
+        };
+
+        let persistent = temporary.exhaust();
+        assert_eq!(persistent.status_name(), "Persistent");
+        assert!(matches!(
+            persistent.into_inner().0,
+            ErrorStatusValue::Temporary
+        ));
+    }
+}